@@ -2,8 +2,10 @@
 //! [`GStreamer Daemon`][1] API.
 //!
 //! [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
-use crate::{gstd_types, resources, Error};
-use reqwest::{Client, Response};
+use std::time::Duration;
+
+use crate::{gstd_types, resources, retry::RetryPolicy, Error};
+use reqwest::{Client, ClientBuilder, Method, Response};
 use url::Url;
 
 /// [`GstClient`] for [`GStreamer Daemon`][1] API.
@@ -13,11 +15,16 @@ use url::Url;
 pub struct GstClient {
     http_client: Client,
     pub(crate) base_url: Url,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl GstClient {
     /// Build [`GstClient`] for future call to [`GStreamer Daemon`][1] API.
     ///
+    /// Uses a [`reqwest::Client`] configured with its defaults. Use
+    /// [`GstClient::builder`] instead if you need to configure timeouts or
+    /// the TLS backend.
+    ///
     /// # Errors
     ///
     /// If incorrect `base_url` passed
@@ -27,17 +34,26 @@ impl GstClient {
         Ok(Self {
             http_client: Client::new(),
             base_url: Url::parse(&base_url.into()).map_err(Error::IncorrectBaseUrl)?,
+            retry_policy: None,
         })
     }
 
+    /// Creates a [`GstClientBuilder`] for configuring the underlying HTTP
+    /// transport (timeouts, TLS backend, certificate validation) before
+    /// building a [`GstClient`] for `base_url`.
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[must_use]
+    pub fn builder<S: Into<String>>(base_url: S) -> GstClientBuilder {
+        GstClientBuilder::new(base_url)
+    }
+
     pub(crate) async fn get(&self, url: &str) -> Result<Response, Error> {
-        self.http_client
-            .get(self.base_url.join(url).map_err(Error::IncorrectApiUrl)?)
-            .send()
-            .await
-            .map_err(Error::RequestFailed)
+        self.send_idempotent(Method::GET, url).await
     }
 
+    /// Pipeline creation is not idempotent, so `POST` requests are never
+    /// retried.
     pub(crate) async fn post(&self, url: &str) -> Result<Response, Error> {
         self.http_client
             .post(self.base_url.join(url).map_err(Error::IncorrectApiUrl)?)
@@ -47,19 +63,47 @@ impl GstClient {
     }
 
     pub(crate) async fn put(&self, url: &str) -> Result<Response, Error> {
-        self.http_client
-            .put(self.base_url.join(url).map_err(Error::IncorrectApiUrl)?)
-            .send()
-            .await
-            .map_err(Error::RequestFailed)
+        self.send_idempotent(Method::PUT, url).await
     }
 
     pub(crate) async fn delete(&self, url: &str) -> Result<Response, Error> {
-        self.http_client
-            .delete(self.base_url.join(url).map_err(Error::IncorrectApiUrl)?)
-            .send()
-            .await
-            .map_err(Error::RequestFailed)
+        self.send_idempotent(Method::DELETE, url).await
+    }
+
+    /// Sends an idempotent request, retrying on a connection error or a
+    /// `5xx` response according to [`GstClient::retry_policy`], if any.
+    async fn send_idempotent(&self, method: Method, url: &str) -> Result<Response, Error> {
+        let full_url = self.base_url.join(url).map_err(Error::IncorrectApiUrl)?;
+
+        let Some(policy) = self.retry_policy else {
+            return self
+                .http_client
+                .request(method, full_url)
+                .send()
+                .await
+                .map_err(Error::RequestFailed);
+        };
+
+        let mut attempt = 0;
+        loop {
+            match self
+                .http_client
+                .request(method.clone(), full_url.clone())
+                .send()
+                .await
+            {
+                Ok(resp) if resp.status().is_server_error() && attempt < policy.max_attempts => {
+                    tokio::time::sleep(policy.delay_for(attempt)).await;
+                    attempt += 1;
+                }
+                Ok(resp) => return Ok(resp),
+                Err(_) if attempt < policy.max_attempts => {
+                    tokio::time::sleep(policy.delay_for(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(Error::RequestFailed(e)),
+            }
+        }
     }
 
     pub(crate) async fn process_resp(&self, resp: Response) -> Result<gstd_types::Response, Error> {
@@ -117,6 +161,7 @@ impl Default for GstClient {
         Self {
             http_client: Client::new(),
             base_url: Url::parse("http://127.0.0.1:5000").unwrap(),
+            retry_policy: None,
         }
     }
 }
@@ -126,6 +171,7 @@ impl From<Url> for GstClient {
         Self {
             http_client: Client::new(),
             base_url: url,
+            retry_policy: None,
         }
     }
 }
@@ -135,10 +181,96 @@ impl From<&Url> for GstClient {
         Self {
             http_client: Client::new(),
             base_url: url.clone(),
+            retry_policy: None,
         }
     }
 }
 
+/// Builder for [`GstClient`], allowing the underlying [`reqwest::Client`]
+/// to be configured before any request is made.
+///
+/// TLS support is controlled the same way [`reqwest`] itself controls it:
+/// via the `default-tls`, `native-tls`, `rustls-tls-webpki-roots` and
+/// `rustls-tls-native-roots` Cargo features, each of which enables the
+/// matching `reqwest` feature of the same name. `default-tls` is enabled
+/// by default; the features are additive, so selecting only another
+/// backend requires disabling default features.
+///
+/// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+#[derive(Debug)]
+pub struct GstClientBuilder {
+    base_url: String,
+    builder: ClientBuilder,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl GstClientBuilder {
+    fn new<S: Into<String>>(base_url: S) -> Self {
+        Self {
+            base_url: base_url.into(),
+            builder: Client::builder(),
+            retry_policy: None,
+        }
+    }
+
+    /// Sets the timeout for every request made through the resulting
+    /// [`GstClient`], covering the whole request/response cycle (connect,
+    /// send, and read).
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing the connection to the
+    /// [`GStreamer Daemon`][1] endpoint.
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Controls whether certificates which do not validate (e.g.
+    /// self-signed certificates on an internal gstd endpoint) are accepted.
+    ///
+    /// # Warning
+    ///
+    /// Accepting invalid certificates disables a critical part of TLS
+    /// security. Only enable this for trusted, locked-down deployments.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.builder = self
+            .builder
+            .danger_accept_invalid_certs(accept_invalid_certs);
+        self
+    }
+
+    /// Configures automatic exponential-backoff retries for idempotent
+    /// requests (`GET`, `PUT`, `DELETE`) made through the resulting
+    /// [`GstClient`]. See [`RetryPolicy`] for what gets retried and when.
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Finalizes the builder, returning the configured [`GstClient`].
+    ///
+    /// # Errors
+    ///
+    /// If incorrect `base_url` passed, or the underlying [`reqwest::Client`]
+    /// fails to build (e.g. the TLS backend could not be initialized).
+    pub fn build(self) -> Result<GstClient, Error> {
+        Ok(GstClient {
+            http_client: self.builder.build().map_err(Error::ClientBuild)?,
+            base_url: Url::parse(&self.base_url).map_err(Error::IncorrectBaseUrl)?,
+            retry_policy: self.retry_policy,
+        })
+    }
+}
+
 #[cfg(test)]
 mod spec {
     use super::*;
@@ -161,6 +293,42 @@ mod spec {
         assert_eq!(client.base_url, expect_url());
     }
 
+    #[test]
+    fn create_client_with_builder() {
+        let client = GstClient::builder(mockito::server_url().as_str())
+            .timeout(std::time::Duration::from_secs(5))
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url, expect_url());
+    }
+
+    #[tokio::test]
+    async fn retries_idempotent_request_on_server_error() {
+        let _m_fail = mock("GET", "/pipelines")
+            .with_status(503)
+            .expect(1)
+            .create();
+        let _m_ok = mock("GET", "/pipelines")
+            .with_body_from_file(format!(
+                "{PROJECT_ROOT}/tests/files/retrieve_pipelines_empty.json"
+            ))
+            .expect(1)
+            .create();
+
+        let client = GstClient::builder(mockito::server_url().as_str())
+            .retry_policy(crate::retry::RetryPolicy::new(
+                3,
+                std::time::Duration::from_millis(1),
+                std::time::Duration::from_millis(10),
+            ))
+            .build()
+            .unwrap();
+
+        let res = client.pipelines().await;
+        assert!(res.is_ok());
+    }
+
     #[test]
     fn create_client_from() {
         let url = expect_url();
@@ -315,16 +483,131 @@ mod spec {
             assert!(res.is_ok());
         };
     }
-    // #[tokio::test]
-    // async fn retrieve_pipeline_bus_read() {
-    //     let _m = mock("GET", format!("/pipelines/{PIPELINE_NAME}").as_str())
-    //             .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/retrieve_element_property.json"))
-    //             .create();
-
-    //     if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
-    //         let res = client.pipeline(PIPELINE_NAME).bus().read().await;
-    //         println!("{:?}", res);
-    //         assert!(res.is_ok());
-    //     };
-    // }
+    #[tokio::test]
+    async fn set_pipeline_element_property() {
+        let _m = mock(
+            "PUT",
+            format!("/pipelines/{PIPELINE_NAME}/elements/videotestsrc0/properties/is-live")
+                .as_str(),
+        )
+        .match_query(Matcher::UrlEncoded("value".into(), "true".into()))
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/update_element_property.json"))
+        .create();
+
+        if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
+            let res = client
+                .pipeline(PIPELINE_NAME)
+                .element("videotestsrc0")
+                .set_property("is-live", true)
+                .await;
+            println!("{:?}", res);
+            assert!(res.is_ok());
+        };
+    }
+
+    #[tokio::test]
+    async fn retrieve_pipeline_bus_read() {
+        let _m = mock(
+            "GET",
+            format!("/pipelines/{PIPELINE_NAME}/bus/message").as_str(),
+        )
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/retrieve_bus_message.json"))
+        .create();
+
+        if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
+            let res = client.pipeline(PIPELINE_NAME).bus().read().await;
+            println!("{:?}", res);
+            assert!(res.is_ok());
+        };
+    }
+
+    #[tokio::test]
+    async fn seek_pipeline() {
+        let _m = mock(
+            "POST",
+            format!("/pipelines/{PIPELINE_NAME}/event").as_str(),
+        )
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("name".into(), "seek".into()),
+            Matcher::UrlEncoded("rate".into(), "1".into()),
+            Matcher::UrlEncoded("format".into(), "3".into()),
+            Matcher::UrlEncoded("flags".into(), "3".into()),
+            Matcher::UrlEncoded("start-type".into(), "1".into()),
+            Matcher::UrlEncoded("start".into(), "0".into()),
+            Matcher::UrlEncoded("stop-type".into(), "0".into()),
+            Matcher::UrlEncoded("stop".into(), "-1".into()),
+        ]))
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/pipeline_event.json"))
+        .create();
+
+        if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
+            let res = client
+                .pipeline(PIPELINE_NAME)
+                .seek(
+                    1.0,
+                    gstd_types::GstFormat::TimeInNanoseconds,
+                    gstd_types::SeekFlags::FLUSH | gstd_types::SeekFlags::ACCURATE,
+                    gstd_types::SeekType::Absolute,
+                    0,
+                    gstd_types::SeekType::None,
+                    -1,
+                )
+                .await;
+            println!("{:?}", res);
+            assert!(res.is_ok());
+        };
+    }
+
+    #[tokio::test]
+    async fn flush_and_eos_pipeline_events() {
+        let _m = mock(
+            "POST",
+            format!("/pipelines/{PIPELINE_NAME}/event").as_str(),
+        )
+        .match_query(Matcher::UrlEncoded("name".into(), "flush-start".into()))
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/pipeline_event.json"))
+        .create();
+        let _m2 = mock(
+            "POST",
+            format!("/pipelines/{PIPELINE_NAME}/event").as_str(),
+        )
+        .match_query(Matcher::UrlEncoded("name".into(), "flush-stop".into()))
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/pipeline_event.json"))
+        .create();
+        let _m3 = mock(
+            "POST",
+            format!("/pipelines/{PIPELINE_NAME}/event").as_str(),
+        )
+        .match_query(Matcher::UrlEncoded("name".into(), "eos".into()))
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/pipeline_event.json"))
+        .create();
+
+        if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
+            let pipeline = client.pipeline(PIPELINE_NAME);
+            assert!(pipeline.flush_start().await.is_ok());
+            assert!(pipeline.flush_stop().await.is_ok());
+            assert!(pipeline.eos().await.is_ok());
+        };
+    }
+
+    #[tokio::test]
+    async fn subscribe_pipeline_bus() {
+        use tokio_stream::StreamExt;
+
+        let _m = mock(
+            "GET",
+            Matcher::Regex(format!("^/pipelines/{PIPELINE_NAME}/bus/message.*")),
+        )
+        .with_body_from_file(format!("{PROJECT_ROOT}/tests/files/retrieve_bus_message.json"))
+        .create();
+
+        if let Ok(client) = GstClient::build(mockito::server_url().as_str()) {
+            let mut stream = client
+                .pipeline(PIPELINE_NAME)
+                .bus()
+                .subscribe("eos+error", std::time::Duration::from_millis(100));
+            let msg = stream.next().await;
+            assert!(matches!(msg, Some(Ok(_))));
+        };
+    }
 }
@@ -0,0 +1,59 @@
+//! [`Error`] type returned by this crate's operations.
+use derive_more::{Display, Error as DeriveError};
+
+use crate::gstd_types;
+
+/// Possible errors returned by [`GstClient`] operations.
+///
+/// [`GstClient`]: crate::GstClient
+#[derive(Debug, Display, DeriveError)]
+pub enum Error {
+    /// Base URL passed to [`GstClient::build`] is not a valid [`Url`].
+    ///
+    /// [`GstClient::build`]: crate::GstClient::build
+    /// [`Url`]: url::Url
+    #[display(fmt = "Incorrect base URL: {_0}")]
+    IncorrectBaseUrl(#[error(source)] url::ParseError),
+
+    /// API path could not be joined onto the client's base URL.
+    #[display(fmt = "Incorrect API URL: {_0}")]
+    IncorrectApiUrl(#[error(source)] url::ParseError),
+
+    /// Underlying HTTP request could not be sent, or failed to complete.
+    #[display(fmt = "Request failed: {_0}")]
+    RequestFailed(#[error(source)] reqwest::Error),
+
+    /// The underlying [`reqwest::Client`] failed to build, e.g. because the
+    /// configured TLS backend could not be initialized. Distinct from
+    /// [`Error::RequestFailed`], which is a runtime failure of an in-flight
+    /// request: this one is a static misconfiguration and retrying it
+    /// without changing the builder configuration will never succeed.
+    ///
+    /// [`reqwest::Client`]: reqwest::Client
+    #[display(fmt = "Failed to build HTTP client: {_0}")]
+    ClientBuild(#[error(source)] reqwest::Error),
+
+    /// [`GStreamer Daemon`][1] responded with a non-success HTTP status.
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[display(fmt = "Bad HTTP status: {_0}")]
+    BadStatus(#[error(not(source))] reqwest::StatusCode),
+
+    /// Response body could not be parsed as the expected [`gstd_types::Response`].
+    #[display(fmt = "Bad response body: {_0}")]
+    BadBody(#[error(source)] reqwest::Error),
+
+    /// [`GStreamer Daemon`][1] processed the request but reported an error
+    /// [`gstd_types::ResponseCode`].
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[display(fmt = "gstd returned an error: {_0}")]
+    GstdError(#[error(source)] gstd_types::ResponseCode),
+
+    /// A value returned by [`GStreamer Daemon`][1] did not match the type
+    /// requested by the caller.
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[display(fmt = "Unexpected value type")]
+    BadValue,
+}
@@ -117,6 +117,79 @@ pub enum PropertyValue {
     Bool(bool),
 }
 
+impl PropertyValue {
+    /// Formats this value the way [`GStreamer Daemon`][1] expects it in a
+    /// `value` query parameter: booleans as `true`/`false`, integers as
+    /// decimal, and strings URL-encoded.
+    ///
+    /// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+    #[must_use]
+    pub fn to_query_value(&self) -> String {
+        match self {
+            Self::Bool(b) => b.to_string(),
+            Self::Integer(i) => i.to_string(),
+            Self::String(s) => url::form_urlencoded::byte_serialize(s.as_bytes()).collect(),
+        }
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for PropertyValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl TryFrom<PropertyValue> for bool {
+    type Error = crate::Error;
+
+    fn try_from(value: PropertyValue) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::Bool(b) => Ok(b),
+            _ => Err(crate::Error::BadValue),
+        }
+    }
+}
+
+impl TryFrom<PropertyValue> for i64 {
+    type Error = crate::Error;
+
+    fn try_from(value: PropertyValue) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::Integer(i) => Ok(i),
+            _ => Err(crate::Error::BadValue),
+        }
+    }
+}
+
+impl TryFrom<PropertyValue> for String {
+    type Error = crate::Error;
+
+    fn try_from(value: PropertyValue) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::String(s) => Ok(s),
+            _ => Err(crate::Error::BadValue),
+        }
+    }
+}
+
 /// Possible result in [`Response::response`] after
 /// `GET /pipelines/{name}/bus/message` API request
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -129,17 +202,25 @@ pub struct Bus {
     pub debug: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+/// Used only as a parameter to [`Pipeline::seek`], never (de)serialized to
+/// JSON, so this is a plain fieldless enum rather than `serde_repr` like
+/// [`ResponseCode`].
+///
+/// [`Pipeline::seek`]: crate::resources::Pipeline::seek
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
 pub enum SeekType {
     None = 0,
     Absolute = 1,
-    Relative = 2
+    Relative = 2,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+/// Used only as a parameter to [`Pipeline::seek`], never (de)serialized to
+/// JSON, so this is a plain fieldless enum rather than `serde_repr` like
+/// [`ResponseCode`].
+///
+/// [`Pipeline::seek`]: crate::resources::Pipeline::seek
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
 pub enum GstFormat {
     Undefined = 0,
@@ -147,25 +228,64 @@ pub enum GstFormat {
     Bytes = 2,
     TimeInNanoseconds = 3,
     Buffers = 4,
-    Percent = 5
+    Percent = 5,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(untagged)]
-#[repr(i32)]
-pub enum SeekFlags {
-    None = 0, // – no flag
-    Flush = 1, // – flush pipeline
-    Accurate = 2, // – accurate position is requested, this might be considerably slower for some formats.
-    KeyUnit = 4, // – seek to the nearest keyframe. This might be faster but less accurate.
-    Segment = 8, // – perform a segment seek.
-    TrickMode = 16, // – when doing fast forward or fast reverse playback, allow elements to skip frames instead of generating all frames. (Since: 1.6)
-    // Skip = 16, // – Deprecated backward compatibility flag, replaced by GST_SEEK_FLAG_TRICKMODE
-    SnapBefore = 32, // – go to a location before the requested position, if GST_SEEK_FLAG_KEY_UNIT this means the keyframe at or before the requested position the one at or before the seek target.
-    SnapAfter = 64, // – go to a location after the requested position, if GST_SEEK_FLAG_KEY_UNIT this means the keyframe at of after the requested position.
-    SnapNearest = 96, // – go to a position near the requested position, if GST_SEEK_FLAG_KEY_UNIT this means the keyframe closest to the requested position, if both keyframes are at an equal distance, behaves like GST_SEEK_FLAG_SNAP_BEFORE.
-    TrickModeKeyUnits = 128, // – when doing fast forward or fast reverse playback, request that elements only decode keyframes and skip all other content, for formats that have keyframes. (Since: 1.6)
-    TrickModeNoAudio = 256, // – when doing fast forward or fast reverse playback, request that audio decoder elements skip decoding and output only gap events or silence. (Since: 1.6)
-    TrickModeForwardPredicted = 512, // – When doing fast forward or fast reverse playback, request that elements only decode keyframes and forward predicted frames and skip all other content (for example B-Frames), for formats that have keyframes and forward predicted frames. (Since: 1.18)
-    InstantRateChange = 1024, //
+bitflags::bitflags! {
+    /// Flags controlling how [`Pipeline::seek`] repositions a pipeline,
+    /// mirroring GStreamer's `GstSeekFlags`. Combine flags with `|`, e.g.
+    /// `SeekFlags::FLUSH | SeekFlags::KEY_UNIT`.
+    ///
+    /// Only ever formatted via [`SeekFlags::bits`] directly into the
+    /// `seek` request's query string, never (de)serialized to JSON.
+    ///
+    /// [`Pipeline::seek`]: crate::resources::Pipeline::seek
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct SeekFlags: i32 {
+        /// No flag.
+        const NONE = 0;
+        /// Flush pipeline.
+        const FLUSH = 1;
+        /// Accurate position is requested, this might be considerably
+        /// slower for some formats.
+        const ACCURATE = 2;
+        /// Seek to the nearest keyframe. This might be faster but less
+        /// accurate.
+        const KEY_UNIT = 4;
+        /// Perform a segment seek.
+        const SEGMENT = 8;
+        /// When doing fast forward or fast reverse playback, allow
+        /// elements to skip frames instead of generating all frames.
+        /// (Since: 1.6)
+        const TRICK_MODE = 16;
+        /// Go to a location before the requested position, if
+        /// [`SeekFlags::KEY_UNIT`] this means the keyframe at or before
+        /// the requested position.
+        const SNAP_BEFORE = 32;
+        /// Go to a location after the requested position, if
+        /// [`SeekFlags::KEY_UNIT`] this means the keyframe at or after the
+        /// requested position.
+        const SNAP_AFTER = 64;
+        /// Go to a position near the requested position, if
+        /// [`SeekFlags::KEY_UNIT`] this means the closest keyframe, if
+        /// both keyframes are at an equal distance, behaves like
+        /// [`SeekFlags::SNAP_BEFORE`].
+        const SNAP_NEAREST = 96;
+        /// When doing fast forward or fast reverse playback, request that
+        /// elements only decode keyframes and skip all other content, for
+        /// formats that have keyframes. (Since: 1.6)
+        const TRICK_MODE_KEY_UNITS = 128;
+        /// When doing fast forward or fast reverse playback, request that
+        /// audio decoder elements skip decoding and output only gap
+        /// events or silence. (Since: 1.6)
+        const TRICK_MODE_NO_AUDIO = 256;
+        /// When doing fast forward or fast reverse playback, request that
+        /// elements only decode keyframes and forward predicted frames
+        /// and skip all other content (e.g. B-Frames), for formats that
+        /// have keyframes and forward predicted frames. (Since: 1.18)
+        const TRICK_MODE_FORWARD_PREDICTED = 512;
+        /// Signals that a rate change should be applied immediately.
+        /// (Since: 1.18)
+        const INSTANT_RATE_CHANGE = 1024;
+    }
 }
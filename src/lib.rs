@@ -0,0 +1,12 @@
+//! Client library for interacting with a [`GStreamer Daemon`][1] instance
+//! over its HTTP API.
+//!
+//! [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+pub mod client;
+mod error;
+pub mod gstd_types;
+pub mod resources;
+pub mod retry;
+
+#[doc(inline)]
+pub use crate::{client::GstClient, error::Error, retry::RetryPolicy};
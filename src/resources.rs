@@ -0,0 +1,437 @@
+//! Resources exposed by the [`GStreamer Daemon`][1] HTTP API: pipelines,
+//! their elements, their bus, and the daemon's debug mode.
+//!
+//! [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+
+use crate::{client::GstClient, gstd_types, Error};
+
+/// Minimum delay between long-poll rounds after a transport error, so a
+/// down/unreachable daemon doesn't turn [`Bus::subscribe`]'s background
+/// task into a tight busy-loop hammering the socket.
+const MIN_POLL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Handle to a single named pipeline managed by [`GStreamer Daemon`][1].
+///
+/// Obtained via [`GstClient::pipeline`].
+///
+/// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    client: GstClient,
+    name: String,
+}
+
+impl Pipeline {
+    pub(crate) fn new<S: Into<String>>(name: S, client: &GstClient) -> Self {
+        Self {
+            client: client.clone(),
+            name: name.into(),
+        }
+    }
+
+    /// Performs `POST /pipelines` API request, creating this pipeline from
+    /// the given GStreamer `description`.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn create<S: AsRef<str>>(
+        &self,
+        description: S,
+    ) -> Result<gstd_types::Response, Error> {
+        let encoded_description: String =
+            url::form_urlencoded::byte_serialize(description.as_ref().as_bytes()).collect();
+        let url = format!("pipelines?name={}&description={encoded_description}", self.name);
+        let resp = self.client.post(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `DELETE /pipelines/{name}` API request, removing this
+    /// pipeline.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn delete(&self) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}", self.name);
+        let resp = self.client.delete(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `GET /pipelines/{name}` API request, returning this
+    /// pipeline's properties.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn properties(&self) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}", self.name);
+        let resp = self.client.get(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `GET /pipelines/{name}/graph` API request, returning this
+    /// pipeline's GraphViz representation.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn graph(&self) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}/graph", self.name);
+        let resp = self.client.get(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `GET /pipelines/{name}/elements` API request, returning
+    /// this pipeline's elements.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn elements(&self) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}/elements", self.name);
+        let resp = self.client.get(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Operate with an element of this pipeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - name of the element
+    #[must_use]
+    pub fn element<S: Into<String>>(&self, name: S) -> Element {
+        Element::new(name, self)
+    }
+
+    /// Operate with this pipeline's bus.
+    #[must_use]
+    pub fn bus(&self) -> Bus {
+        Bus::new(self)
+    }
+
+    /// Performs `POST /pipelines/{name}/event?name=seek&...` API request,
+    /// repositioning playback.
+    ///
+    /// `start` and `stop` are interpreted according to `format` (e.g.
+    /// nanoseconds for [`gstd_types::GstFormat::TimeInNanoseconds`]), and
+    /// `start_type`/`stop_type` control whether they are absolute or
+    /// relative to the current position. `flags` may combine multiple
+    /// [`gstd_types::SeekFlags`], e.g.
+    /// `SeekFlags::FLUSH | SeekFlags::KEY_UNIT`.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn seek(
+        &self,
+        rate: f64,
+        format: gstd_types::GstFormat,
+        flags: gstd_types::SeekFlags,
+        start_type: gstd_types::SeekType,
+        start: i64,
+        stop_type: gstd_types::SeekType,
+        stop: i64,
+    ) -> Result<gstd_types::Response, Error> {
+        let url = format!(
+            "pipelines/{}/event?name=seek&rate={rate}&format={}&flags={}&start-type={}&\
+             start={start}&stop-type={}&stop={stop}",
+            self.name,
+            format as i32,
+            flags.bits(),
+            start_type as i32,
+            stop_type as i32,
+        );
+        let resp = self.client.post(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `POST /pipelines/{name}/event?name=flush-start` API
+    /// request, starting a flush.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn flush_start(&self) -> Result<gstd_types::Response, Error> {
+        self.event("flush-start").await
+    }
+
+    /// Performs `POST /pipelines/{name}/event?name=flush-stop` API
+    /// request, ending a flush.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn flush_stop(&self) -> Result<gstd_types::Response, Error> {
+        self.event("flush-stop").await
+    }
+
+    /// Performs `POST /pipelines/{name}/event?name=eos` API request,
+    /// pushing an end-of-stream event into the pipeline.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn eos(&self) -> Result<gstd_types::Response, Error> {
+        self.event("eos").await
+    }
+
+    async fn event(&self, name: &str) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}/event?name={name}", self.name);
+        let resp = self.client.post(&url).await?;
+        self.client.process_resp(resp).await
+    }
+}
+
+/// Handle to an element of a [`Pipeline`].
+///
+/// Obtained via [`Pipeline::element`].
+#[derive(Debug, Clone)]
+pub struct Element {
+    client: GstClient,
+    pipeline_name: String,
+    name: String,
+}
+
+impl Element {
+    pub(crate) fn new<S: Into<String>>(name: S, pipeline: &Pipeline) -> Self {
+        Self {
+            client: pipeline.client.clone(),
+            pipeline_name: pipeline.name.clone(),
+            name: name.into(),
+        }
+    }
+
+    /// Performs `GET /pipelines/{name}/elements/{el}/properties/{prop}` API
+    /// request, returning the requested `property`'s current value.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn property<S: AsRef<str>>(
+        &self,
+        property: S,
+    ) -> Result<gstd_types::Response, Error> {
+        let url = format!(
+            "pipelines/{}/elements/{}/properties/{}",
+            self.pipeline_name,
+            self.name,
+            property.as_ref(),
+        );
+        let resp = self.client.get(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Like [`Element::property`], but parses the returned
+    /// [`gstd_types::Property::value`] into a caller-chosen type.
+    ///
+    /// # Errors
+    ///
+    /// If the API request fails, see [`Error`] for details. Returns
+    /// [`Error::BadValue`] if the property's value is not of type `T`.
+    pub async fn get_property_typed<T>(&self, property: impl AsRef<str>) -> Result<T, Error>
+    where
+        T: TryFrom<gstd_types::PropertyValue, Error = Error>,
+    {
+        let resp = self.property(property).await?;
+        match resp.response {
+            gstd_types::ResponseT::Property(p) => T::try_from(p.value),
+            _ => Err(Error::BadValue),
+        }
+    }
+
+    /// Performs `PUT /pipelines/{name}/elements/{el}/properties/{prop}`
+    /// API request, setting `property` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn set_property<V: Into<gstd_types::PropertyValue>>(
+        &self,
+        property: impl AsRef<str>,
+        value: V,
+    ) -> Result<gstd_types::Response, Error> {
+        let url = format!(
+            "pipelines/{}/elements/{}/properties/{}?value={}",
+            self.pipeline_name,
+            self.name,
+            property.as_ref(),
+            value.into().to_query_value(),
+        );
+        let resp = self.client.put(&url).await?;
+        self.client.process_resp(resp).await
+    }
+}
+
+/// Handle to a [`Pipeline`]'s bus.
+///
+/// Obtained via [`Pipeline::bus`].
+#[derive(Debug, Clone)]
+pub struct Bus {
+    client: GstClient,
+    pipeline_name: String,
+}
+
+impl Bus {
+    pub(crate) fn new(pipeline: &Pipeline) -> Self {
+        Self {
+            client: pipeline.client.clone(),
+            pipeline_name: pipeline.name.clone(),
+        }
+    }
+
+    /// Performs `GET /pipelines/{name}/bus/message` API request, reading
+    /// (and consuming) the next message on the bus, if any.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn read(&self) -> Result<gstd_types::Response, Error> {
+        let url = format!("pipelines/{}/bus/message", self.pipeline_name);
+        let resp = self.client.get(&url).await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Subscribes to this pipeline's bus, returning a [`Stream`] of
+    /// [`gstd_types::Bus`] messages.
+    ///
+    /// Internally long-polls `GET /pipelines/{name}/bus/message` in a
+    /// background task, honoring the given `types` filter (a `+`-separated
+    /// list of message types gstd understands, e.g.
+    /// `"eos+error+state-changed"`) and `timeout` for each poll. Transport
+    /// errors are yielded as `Err` items without ending the stream, with a
+    /// minimum delay between rounds so a down daemon doesn't cause a
+    /// busy-loop; polling only stops when the returned stream is dropped,
+    /// which cancels the in-flight request.
+    ///
+    /// # Panics
+    ///
+    /// If called outside of a Tokio runtime.
+    pub fn subscribe<S: Into<String>>(&self, types: S, timeout: Duration) -> BusSubscription {
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        let client = self.client.clone();
+        let pipeline_name = self.pipeline_name.clone();
+        let types = types.into();
+
+        let task = tokio::spawn(async move {
+            let url = format!(
+                "pipelines/{pipeline_name}/bus/message?types={types}&timeout={}",
+                timeout.as_millis(),
+            );
+            loop {
+                let item = match client.get(&url).await {
+                    Ok(resp) => match client.process_resp(resp).await {
+                        Ok(gstd_types::Response {
+                            response: gstd_types::ResponseT::Bus(Some(msg)),
+                            ..
+                        }) => Some(Ok(msg)),
+                        // No message arrived before gstd's own timeout elapsed;
+                        // keep long-polling without surfacing anything.
+                        Ok(_) => None,
+                        Err(e) => Some(Err(e)),
+                    },
+                    Err(e) => Some(Err(e)),
+                };
+                let is_err = matches!(item, Some(Err(_)));
+                if let Some(item) = item {
+                    if tx.send(item).await.is_err() {
+                        break;
+                    }
+                }
+                if is_err {
+                    tokio::time::sleep(MIN_POLL_BACKOFF).await;
+                }
+            }
+        });
+
+        BusSubscription {
+            inner: ReceiverStream::new(rx),
+            task,
+        }
+    }
+}
+
+/// [`Stream`] of [`gstd_types::Bus`] messages returned by [`Bus::subscribe`].
+///
+/// Dropping this stream cancels the background long-polling task.
+pub struct BusSubscription {
+    inner: ReceiverStream<Result<gstd_types::Bus, Error>>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl Stream for BusSubscription {
+    type Item = Result<gstd_types::Bus, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+impl Drop for BusSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Handle to [`GStreamer Daemon`][1]'s debug mode.
+///
+/// Obtained via [`GstClient::debug`].
+///
+/// [1]: https://developer.ridgerun.com/wiki/index.php/GStreamer_Daemon
+#[derive(Debug, Clone)]
+pub struct Debug {
+    client: GstClient,
+}
+
+impl Debug {
+    pub(crate) fn new(client: &GstClient) -> Self {
+        Self {
+            client: client.clone(),
+        }
+    }
+
+    /// Performs `GET /debug/enable` API request, returning whether debug
+    /// mode is currently enabled.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn enabled(&self) -> Result<gstd_types::Response, Error> {
+        let resp = self.client.get("debug/enable").await?;
+        self.client.process_resp(resp).await
+    }
+
+    /// Performs `PUT /debug/enable?name=true|false` API request, enabling
+    /// or disabling debug mode.
+    ///
+    /// # Errors
+    ///
+    /// If API request cannot be performed, or fails.
+    /// See [`Error`] for details.
+    pub async fn set_enabled(&self, enabled: bool) -> Result<gstd_types::Response, Error> {
+        let url = format!("debug/enable?name={enabled}");
+        let resp = self.client.put(&url).await?;
+        self.client.process_resp(resp).await
+    }
+}
@@ -0,0 +1,56 @@
+//! Exponential-backoff retry policy for idempotent requests.
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Retry policy applied by [`GstClient`] to idempotent requests (`GET`,
+/// `PUT`, `DELETE`) on transport errors or a `5xx` response: connection
+/// resets and daemon restarts during IPC warm-up are common when driving
+/// gstd during system bring-up, and this lets callers recover without
+/// writing their own retry loop around every call.
+///
+/// Non-idempotent requests, such as `POST /pipelines` pipeline creation,
+/// are never retried, and a definitive gstd [`gstd_types::ResponseCode`]
+/// error (a successful HTTP response carrying an error code in its body)
+/// is never retried either, since retrying it would just repeat the same
+/// failure.
+///
+/// [`GstClient`]: crate::GstClient
+/// [`gstd_types::ResponseCode`]: crate::gstd_types::ResponseCode
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a [`RetryPolicy`] that retries up to `max_attempts` times,
+    /// sleeping `min(base_delay * 2^attempt, max_delay)` plus random
+    /// jitter between attempts.
+    #[must_use]
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    pub(crate) fn delay_for(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter =
+            Duration::from_millis(rand::thread_rng().gen_range(0..=exp.as_millis() as u64 / 4 + 1));
+        exp + jitter
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Up to 3 retries, starting at 100ms and capping at 5s.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(100), Duration::from_secs(5))
+    }
+}